@@ -1,4 +1,62 @@
+use std::str::FromStr;
+
 use argh::FromArgs;
+use serde::Deserialize;
+
+/// Whether floating windows participate in edge and jump-target selection.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum ConsiderFloating {
+    #[default]
+    ExcludeFloating,
+    IncludeFloating,
+}
+
+impl ConsiderFloating {
+    pub fn from_switch(include_floating: bool) -> Self {
+        if include_floating {
+            Self::IncludeFloating
+        } else {
+            Self::ExcludeFloating
+        }
+    }
+
+    /// Resolves `--include-floating`/`--exclude-floating` against the config
+    /// default. `--exclude-floating` always wins (it's the only way to turn a
+    /// config default of `true` back off for a single invocation); otherwise
+    /// either the CLI switch or the config default turns floating windows on.
+    pub fn from_cli(include_floating: bool, exclude_floating: bool, config_include_floating: bool) -> Self {
+        if exclude_floating {
+            Self::ExcludeFloating
+        } else {
+            Self::from_switch(include_floating || config_include_floating)
+        }
+    }
+}
+
+/// Whether a cross-workspace jump picks its target from the destination
+/// workspace alone, or from the whole layout.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default, Deserialize)]
+pub enum ConsiderWindows {
+    #[default]
+    #[serde(rename = "current")]
+    CurrentWorkspace,
+    #[serde(rename = "all")]
+    AllWorkspaces,
+}
+
+impl FromStr for ConsiderWindows {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "current" => Ok(Self::CurrentWorkspace),
+            "all" => Ok(Self::AllWorkspaces),
+            other => Err(format!(
+                "invalid --consider value `{other}` (expected `current` or `all`)"
+            )),
+        }
+    }
+}
 
 /// simple horizontal navigation in hyprland
 #[derive(FromArgs)]
@@ -14,6 +72,7 @@ pub enum Command {
     Left(CommandLeft),
     Up(CommandUp),
     Down(CommandDown),
+    Daemon(CommandDaemon),
 }
 
 /// Focus on the next window. If the current window is already at the edge, focus on the next workspace.
@@ -27,6 +86,41 @@ pub struct CommandRight {
         description = "window border size. Necessary for boundary detection"
     )]
     pub bordersize: Option<i32>,
+    #[argh(
+        switch,
+        description = "jump to the most-recently-used workspace instead of the numeric neighbor (requires `hyprnavi-psm daemon` running)"
+    )]
+    pub mru: bool,
+    #[argh(
+        switch,
+        description = "let floating windows participate in edge and jump-target selection"
+    )]
+    pub include_floating: bool,
+    #[argh(
+        switch,
+        description = "exclude floating windows even if the config file defaults to including them"
+    )]
+    pub exclude_floating: bool,
+    #[argh(
+        option,
+        description = "how cross-workspace jumps pick a target: \"current\" (default) or \"all\" workspaces; falls back to the config file, then \"current\""
+    )]
+    pub consider: Option<ConsiderWindows>,
+    #[argh(
+        option,
+        description = "skip windows whose class or title match this glob pattern (e.g. \"*picture-in-picture*\")"
+    )]
+    pub skip_class: Option<String>,
+    #[argh(
+        option,
+        description = "only consider windows whose class or title match this glob pattern"
+    )]
+    pub only_class: Option<String>,
+    #[argh(
+        switch,
+        description = "at a monitor boundary, jump to the adjacent monitor by physical layout instead of wrapping to the next numeric workspace"
+    )]
+    pub spatial: bool,
 }
 
 /// Focus on the previous window. If the current window is already at the edge, focus on the previous workspace.
@@ -40,6 +134,41 @@ pub struct CommandLeft {
         description = "window border size. Necessary for boundary detection"
     )]
     pub bordersize: Option<i32>,
+    #[argh(
+        switch,
+        description = "jump to the most-recently-used workspace instead of the numeric neighbor (requires `hyprnavi-psm daemon` running)"
+    )]
+    pub mru: bool,
+    #[argh(
+        switch,
+        description = "let floating windows participate in edge and jump-target selection"
+    )]
+    pub include_floating: bool,
+    #[argh(
+        switch,
+        description = "exclude floating windows even if the config file defaults to including them"
+    )]
+    pub exclude_floating: bool,
+    #[argh(
+        option,
+        description = "how cross-workspace jumps pick a target: \"current\" (default) or \"all\" workspaces; falls back to the config file, then \"current\""
+    )]
+    pub consider: Option<ConsiderWindows>,
+    #[argh(
+        option,
+        description = "skip windows whose class or title match this glob pattern (e.g. \"*picture-in-picture*\")"
+    )]
+    pub skip_class: Option<String>,
+    #[argh(
+        option,
+        description = "only consider windows whose class or title match this glob pattern"
+    )]
+    pub only_class: Option<String>,
+    #[argh(
+        switch,
+        description = "at a monitor boundary, jump to the adjacent monitor by physical layout instead of wrapping to the next numeric workspace"
+    )]
+    pub spatial: bool,
 }
 
 /// Focus on the next window. If the current window is already at the edge, focus on the next workspace.
@@ -48,6 +177,36 @@ pub struct CommandLeft {
 pub struct CommandUp {
     #[argh(switch, description = "swap window")]
     pub swap: bool,
+    #[argh(
+        switch,
+        description = "let floating windows participate in edge and jump-target selection"
+    )]
+    pub include_floating: bool,
+    #[argh(
+        switch,
+        description = "exclude floating windows even if the config file defaults to including them"
+    )]
+    pub exclude_floating: bool,
+    #[argh(
+        option,
+        description = "how cross-workspace jumps pick a target: \"current\" (default) or \"all\" workspaces; falls back to the config file, then \"current\""
+    )]
+    pub consider: Option<ConsiderWindows>,
+    #[argh(
+        option,
+        description = "skip windows whose class or title match this glob pattern (e.g. \"*picture-in-picture*\")"
+    )]
+    pub skip_class: Option<String>,
+    #[argh(
+        option,
+        description = "only consider windows whose class or title match this glob pattern"
+    )]
+    pub only_class: Option<String>,
+    #[argh(
+        switch,
+        description = "at a monitor boundary, jump to the adjacent monitor by physical layout instead of leaving focus movement to Hyprland"
+    )]
+    pub spatial: bool,
 }
 
 /// Focus on the next window. If the current window is already at the edge, focus on the next workspace.
@@ -56,4 +215,40 @@ pub struct CommandUp {
 pub struct CommandDown {
     #[argh(switch, description = "swap window")]
     pub swap: bool,
+    #[argh(
+        switch,
+        description = "let floating windows participate in edge and jump-target selection"
+    )]
+    pub include_floating: bool,
+    #[argh(
+        switch,
+        description = "exclude floating windows even if the config file defaults to including them"
+    )]
+    pub exclude_floating: bool,
+    #[argh(
+        option,
+        description = "how cross-workspace jumps pick a target: \"current\" (default) or \"all\" workspaces; falls back to the config file, then \"current\""
+    )]
+    pub consider: Option<ConsiderWindows>,
+    #[argh(
+        option,
+        description = "skip windows whose class or title match this glob pattern (e.g. \"*picture-in-picture*\")"
+    )]
+    pub skip_class: Option<String>,
+    #[argh(
+        option,
+        description = "only consider windows whose class or title match this glob pattern"
+    )]
+    pub only_class: Option<String>,
+    #[argh(
+        switch,
+        description = "at a monitor boundary, jump to the adjacent monitor by physical layout instead of leaving focus movement to Hyprland"
+    )]
+    pub spatial: bool,
 }
+
+/// Run as a long-lived background process that tracks MRU window/workspace
+/// history for the `--mru` navigation mode. Exits when Hyprland does.
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "daemon")]
+pub struct CommandDaemon {}
@@ -0,0 +1,103 @@
+//! Optional user config read from `~/.config/hyprnavi-psm/config.toml`.
+//!
+//! Supplies defaults for per-command flags and a per-workspace monitor
+//! affinity table. CLI flags always take precedence over the config, and a
+//! missing (or unparsable) config file preserves today's behavior exactly.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::cli::ConsiderWindows;
+
+/// Parsed contents of `config.toml`. Every field is optional so a partial
+/// config only overrides what it actually sets.
+///
+/// Deliberately missing: a default detection mode (pixel/position/scroller).
+/// That distinction only ever existed in the orphaned `edge.rs` experiment, which
+/// was removed rather than wired in (see the chunk0-2/chunk0-3/chunk1-1
+/// commits) — the live code path only ever does pixel-boundary detection via
+/// `is_bound`, so there is no mode to default.
+#[derive(Deserialize, Default)]
+pub struct Config {
+    /// Default `--bordersize` for nav subcommands that don't pass one.
+    pub bordersize: Option<i32>,
+    /// Default `--include-floating` when the CLI switch isn't passed.
+    #[serde(default)]
+    pub include_floating: bool,
+    /// Default `--consider` mode when the CLI doesn't override it.
+    pub consider: Option<ConsiderWindows>,
+    /// Maps a workspace id (as a string) or name to the monitor name it
+    /// should be pushed to when `--swap` moves a window off an edge.
+    #[serde(default)]
+    pub workspace_affinity: HashMap<String, String>,
+}
+
+impl Config {
+    /// Load the config file, falling back to defaults if it doesn't exist
+    /// or fails to parse (a bad config file shouldn't break navigation).
+    pub fn load() -> Self {
+        let Some(path) = config_path() else {
+            return Self::default();
+        };
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            return Self::default();
+        };
+        toml::from_str(&contents).unwrap_or_default()
+    }
+
+    /// Look up the preferred monitor for a workspace, by id first, then name.
+    pub fn monitor_for_workspace(&self, workspace_id: i32, workspace_name: &str) -> Option<&str> {
+        self.workspace_affinity
+            .get(&workspace_id.to_string())
+            .or_else(|| self.workspace_affinity.get(workspace_name))
+            .map(String::as_str)
+    }
+}
+
+/// `$XDG_CONFIG_HOME/hyprnavi-psm/config.toml`, falling back to `~/.config`.
+fn config_path() -> Option<PathBuf> {
+    let config_home = std::env::var("XDG_CONFIG_HOME").map(PathBuf::from).ok().or_else(|| {
+        std::env::var("HOME")
+            .ok()
+            .map(|home| PathBuf::from(home).join(".config"))
+    })?;
+    Some(config_home.join("hyprnavi-psm").join("config.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn monitor_for_workspace_matches_by_id() {
+        let mut config = Config::default();
+        config.workspace_affinity.insert("3".to_string(), "DP-1".to_string());
+
+        assert_eq!(config.monitor_for_workspace(3, "code"), Some("DP-1"));
+    }
+
+    #[test]
+    fn monitor_for_workspace_matches_by_name() {
+        let mut config = Config::default();
+        config.workspace_affinity.insert("code".to_string(), "DP-1".to_string());
+
+        assert_eq!(config.monitor_for_workspace(3, "code"), Some("DP-1"));
+    }
+
+    #[test]
+    fn monitor_for_workspace_id_wins_over_name() {
+        let mut config = Config::default();
+        config.workspace_affinity.insert("3".to_string(), "DP-1".to_string());
+        config.workspace_affinity.insert("code".to_string(), "HDMI-A-1".to_string());
+
+        assert_eq!(config.monitor_for_workspace(3, "code"), Some("DP-1"));
+    }
+
+    #[test]
+    fn monitor_for_workspace_none_when_unmatched() {
+        let config = Config::default();
+        assert_eq!(config.monitor_for_workspace(3, "code"), None);
+    }
+}
@@ -0,0 +1,314 @@
+//! Background daemon that tracks most-recently-used windows and workspaces.
+//!
+//! Connects to Hyprland's event socket and maintains MRU ordering so that
+//! edge navigation can jump to the previously-used neighbor instead of the
+//! numerically-next one. State is persisted to tmpfs so the (short-lived)
+//! CLI client can read it without talking to the daemon directly.
+
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::UnixStream;
+use std::time::{Duration, SystemTime};
+
+use hyprland::{
+    data::{Client, Clients, Workspaces},
+    shared::HyprData,
+};
+
+use crate::cli::ConsiderFloating;
+use crate::predicate::WindowFilter;
+
+/// Maximum number of entries kept in each MRU list.
+const HISTORY_LEN: usize = 32;
+
+/// How often the daemon re-persists its state even without a new event, so
+/// the cache's mtime reflects that the daemon is alive, not just that
+/// something changed recently (a user can sit on one window far longer than
+/// `STALE_AFTER` without the daemon having died).
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Cache is considered stale after this long without an update, which
+/// usually means the daemon isn't running anymore. Comfortably larger than
+/// `HEARTBEAT_INTERVAL` so a single missed heartbeat doesn't flip this.
+const STALE_AFTER: Duration = Duration::from_secs(10);
+
+/// In-memory MRU state tracked by the daemon.
+#[derive(Default)]
+struct MruState {
+    windows: VecDeque<String>,
+    workspaces: VecDeque<String>,
+}
+
+impl MruState {
+    fn touch_window(&mut self, addr: &str) {
+        touch(&mut self.windows, addr);
+    }
+
+    fn touch_workspace(&mut self, name: &str) {
+        touch(&mut self.workspaces, name);
+    }
+
+    fn serialize(&self) -> String {
+        format!(
+            "windows:{}\nworkspaces:{}\n",
+            self.windows.iter().cloned().collect::<Vec<_>>().join(","),
+            self.workspaces
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(","),
+        )
+    }
+}
+
+/// Moves (or inserts) `value` to the front of the deque, capping its length.
+fn touch(deque: &mut VecDeque<String>, value: &str) {
+    deque.retain(|v| v != value);
+    deque.push_front(value.to_string());
+    deque.truncate(HISTORY_LEN);
+}
+
+/// Run the daemon: connect to the Hyprland event socket and persist MRU
+/// state on every relevant event, plus a periodic heartbeat so the cache's
+/// mtime tracks daemon liveness rather than event recency. Returns `Ok(())`
+/// once the compositor closes the socket; returns an error if the socket
+/// can't be reached, a read fails, or the cache can't be written.
+pub fn run() -> anyhow::Result<()> {
+    let socket_path = event_socket_path()?;
+    let stream = UnixStream::connect(&socket_path)?;
+    stream.set_read_timeout(Some(HEARTBEAT_INTERVAL))?;
+    let mut reader = BufReader::new(stream);
+
+    let mut state = MruState::default();
+    // Establish an initial heartbeat immediately, rather than waiting for the
+    // first event or the first timeout.
+    persist(&state)?;
+
+    let mut line = String::new();
+    loop {
+        let bytes_read = match reader.read_line(&mut line) {
+            Ok(n) => n,
+            // A timed-out read may have already buffered part of the next
+            // event line into `line` — leave it in place so the next
+            // `read_line` call resumes where this one left off, instead of
+            // discarding the partial line and splicing its tail onto
+            // whatever comes after.
+            Err(e) if is_timeout(&e) => {
+                persist(&state)?;
+                continue;
+            }
+            Err(e) => return Err(e.into()),
+        };
+        if bytes_read == 0 {
+            // Compositor closed the socket (e.g. Hyprland exited).
+            return Ok(());
+        }
+
+        let changed = match line.trim_end().split_once(">>") {
+            Some(("activewindowv2", data)) if !data.is_empty() => {
+                state.touch_window(data);
+                true
+            }
+            Some(("workspace", data)) if !data.is_empty() => {
+                // `data` is the workspace *name*, which for named workspaces
+                // isn't numeric — resolve it to an id so consumers can rely
+                // on `mru_adjacent_workspace` parsing cleanly.
+                match resolve_workspace_id(data) {
+                    Some(id) => {
+                        state.touch_workspace(&id.to_string());
+                        true
+                    }
+                    None => false,
+                }
+            }
+            _ => false,
+        };
+
+        if changed {
+            persist(&state)?;
+        }
+        line.clear();
+    }
+}
+
+/// Whether a socket read error is a read-timeout, as opposed to a real I/O
+/// failure.
+fn is_timeout(err: &std::io::Error) -> bool {
+    matches!(err.kind(), std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut)
+}
+
+/// Resolves a workspace's `name` (as reported by the `workspace>>NAME` event)
+/// to its numeric id. Returns `None` if the workspace can't be found (e.g. it
+/// was destroyed before we could query it), in which case the event is
+/// dropped rather than caching an unresolved name.
+fn resolve_workspace_id(name: &str) -> Option<i32> {
+    Workspaces::get()
+        .ok()?
+        .iter()
+        .find(|w| w.name == name)
+        .map(|w| w.id)
+}
+
+/// Path to Hyprland's event socket for the current instance.
+fn event_socket_path() -> anyhow::Result<String> {
+    let sig = std::env::var("HYPRLAND_INSTANCE_SIGNATURE")
+        .map_err(|_| anyhow::anyhow!("HYPRLAND_INSTANCE_SIGNATURE is not set"))?;
+    let runtime_dir = std::env::var("XDG_RUNTIME_DIR").unwrap_or_else(|_| "/tmp".to_string());
+    Ok(format!("{}/hypr/{}/.socket2.sock", runtime_dir, sig))
+}
+
+/// Path to the persisted MRU cache file in `$XDG_RUNTIME_DIR` (or `/tmp`),
+/// keyed by the Hyprland instance signature.
+fn cache_path() -> Option<String> {
+    let sig = std::env::var("HYPRLAND_INSTANCE_SIGNATURE").ok()?;
+    let runtime_dir = std::env::var("XDG_RUNTIME_DIR").unwrap_or_else(|_| "/tmp".to_string());
+    Some(format!("{}/hyprnavi-mru-{}", runtime_dir, sig))
+}
+
+/// Write the MRU state to the cache file atomically (write to a temp file,
+/// then rename over the real path) so readers never see a partial write.
+fn persist(state: &MruState) -> anyhow::Result<()> {
+    let Some(path) = cache_path() else {
+        return Ok(());
+    };
+    let tmp_path = format!("{}.tmp", path);
+    std::fs::File::create(&tmp_path)?.write_all(state.serialize().as_bytes())?;
+    std::fs::rename(&tmp_path, &path)?;
+    Ok(())
+}
+
+/// MRU lists as read back by the CLI client.
+pub struct MruCache {
+    pub windows: Vec<String>,
+    pub workspaces: Vec<String>,
+}
+
+/// Read the persisted MRU cache, returning `None` if the daemon has never
+/// run or the cache is older than `STALE_AFTER` (i.e. the daemon is likely
+/// not running anymore).
+pub fn read_cache() -> Option<MruCache> {
+    let path = cache_path()?;
+    let metadata = std::fs::metadata(&path).ok()?;
+    let age = SystemTime::now().duration_since(metadata.modified().ok()?).ok()?;
+    if age > STALE_AFTER {
+        return None;
+    }
+
+    let contents = std::fs::read_to_string(&path).ok()?;
+    let mut windows = Vec::new();
+    let mut workspaces = Vec::new();
+    for line in contents.lines() {
+        if let Some(rest) = line.strip_prefix("windows:") {
+            windows = rest.split(',').filter(|s| !s.is_empty()).map(String::from).collect();
+        } else if let Some(rest) = line.strip_prefix("workspaces:") {
+            workspaces = rest.split(',').filter(|s| !s.is_empty()).map(String::from).collect();
+        }
+    }
+    Some(MruCache { windows, workspaces })
+}
+
+/// Pick the most-recently-used workspace to jump to from an edge, excluding
+/// the workspace we're currently on. Returns `None` when there's no other
+/// workspace in the MRU history (falls back to numeric wrapping).
+pub fn mru_adjacent_workspace(cache: &MruCache, active_ws_id: i32) -> Option<i32> {
+    let active = active_ws_id.to_string();
+    cache
+        .workspaces
+        .iter()
+        .find(|id| **id != active)
+        .and_then(|id| id.parse().ok())
+}
+
+/// Finds the most-recently-used window that's still open on `workspace_id`,
+/// in MRU order, respecting `consider_floating`/`filter`. Returns `None` if
+/// none of the cached addresses are both eligible and still open there, so
+/// callers can fall back to a boundary heuristic like `arrival_client`.
+pub fn mru_window_on_workspace<'a>(
+    cache: &MruCache,
+    clients: &'a Clients,
+    workspace_id: i32,
+    consider_floating: ConsiderFloating,
+    filter: &WindowFilter,
+) -> Option<&'a Client> {
+    cache.windows.iter().find_map(|addr| {
+        clients.iter().find(|c| {
+            c.address.to_string() == *addr
+                && c.workspace.id == workspace_id
+                && (consider_floating == ConsiderFloating::IncludeFloating || !c.floating)
+                && filter.allows(&c.class, &c.title)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_cache(contents: &str) -> MruCache {
+        let mut windows = Vec::new();
+        let mut workspaces = Vec::new();
+        for line in contents.lines() {
+            if let Some(rest) = line.strip_prefix("windows:") {
+                windows = rest.split(',').filter(|s| !s.is_empty()).map(String::from).collect();
+            } else if let Some(rest) = line.strip_prefix("workspaces:") {
+                workspaces = rest.split(',').filter(|s| !s.is_empty()).map(String::from).collect();
+            }
+        }
+        MruCache { windows, workspaces }
+    }
+
+    #[test]
+    fn touch_moves_existing_entry_to_front() {
+        let mut deque = VecDeque::from(["a".to_string(), "b".to_string(), "c".to_string()]);
+        touch(&mut deque, "b");
+        assert_eq!(deque, VecDeque::from(["b".to_string(), "a".to_string(), "c".to_string()]));
+    }
+
+    #[test]
+    fn touch_caps_history_length() {
+        let mut deque = VecDeque::new();
+        for i in 0..HISTORY_LEN + 5 {
+            touch(&mut deque, &i.to_string());
+        }
+        assert_eq!(deque.len(), HISTORY_LEN);
+        assert_eq!(deque.front().unwrap(), &(HISTORY_LEN + 4).to_string());
+    }
+
+    #[test]
+    fn serialize_round_trips_through_parse_cache() {
+        let mut state = MruState::default();
+        state.touch_window("0x1");
+        state.touch_window("0x2");
+        state.touch_workspace("1");
+        state.touch_workspace("2");
+
+        let cache = parse_cache(&state.serialize());
+        assert_eq!(cache.windows, vec!["0x2", "0x1"]);
+        assert_eq!(cache.workspaces, vec!["2", "1"]);
+    }
+
+    #[test]
+    fn serialize_empty_state_round_trips_to_empty_lists() {
+        let cache = parse_cache(&MruState::default().serialize());
+        assert!(cache.windows.is_empty());
+        assert!(cache.workspaces.is_empty());
+    }
+
+    #[test]
+    fn mru_adjacent_workspace_skips_the_active_workspace() {
+        let cache = MruCache {
+            windows: vec![],
+            workspaces: vec!["3".to_string(), "5".to_string()],
+        };
+        assert_eq!(mru_adjacent_workspace(&cache, 3), Some(5));
+    }
+
+    #[test]
+    fn mru_adjacent_workspace_none_when_only_active_is_cached() {
+        let cache = MruCache {
+            windows: vec![],
+            workspaces: vec!["3".to_string()],
+        };
+        assert_eq!(mru_adjacent_workspace(&cache, 3), None);
+    }
+}
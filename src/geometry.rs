@@ -0,0 +1,206 @@
+//! Geometric nearest-window selection for directional focus.
+//!
+//! Rather than delegating to Hyprland's built-in `MoveFocus` heuristics, this
+//! picks the focus target directly from window rectangles, i3-style: from the
+//! focused window, only candidates whose projection lies strictly toward the
+//! requested direction qualify; candidates that overlap the focused window on
+//! the perpendicular axis are preferred over ones that don't. Among
+//! overlapping candidates the smallest gap along the primary axis wins, then
+//! the smallest perpendicular center offset; among non-overlapping ones the
+//! smallest sum of the two wins.
+
+use hyprland::{data::Client, dispatch::Direction};
+
+/// Anything with an axis-aligned rectangle in Hyprland's coordinate space, so
+/// the selection algorithm isn't tied to a particular window representation.
+pub trait Positioned {
+    fn rect(&self) -> Rect;
+}
+
+/// Axis-aligned window rectangle.
+#[derive(Clone, Copy)]
+pub struct Rect {
+    pub x: i32,
+    pub y: i32,
+    pub w: i32,
+    pub h: i32,
+}
+
+impl Positioned for Client {
+    fn rect(&self) -> Rect {
+        Rect {
+            x: self.at.0 as i32,
+            y: self.at.1 as i32,
+            w: self.size.0 as i32,
+            h: self.size.1 as i32,
+        }
+    }
+}
+
+/// Picks the best focus target in `direction` from `focused`'s rectangle
+/// among `candidates`. Returns `None` if no candidate lies toward
+/// `direction`, which the caller should treat as an edge.
+pub fn nearest_in_direction<'a, F: Positioned, T: Positioned>(
+    focused: &F,
+    candidates: impl Iterator<Item = &'a T>,
+    direction: Direction,
+) -> Option<&'a T> {
+    let origin = focused.rect();
+
+    // (overlaps perpendicular axis, primary-axis gap, perpendicular center offset)
+    let mut best: Option<(&T, bool, i32, i32)> = None;
+
+    for candidate in candidates {
+        let rect = candidate.rect();
+        let Some((primary_gap, perp_offset, overlaps)) = project(&origin, &rect, direction) else {
+            continue;
+        };
+
+        let is_better = match best {
+            None => true,
+            Some((_, best_overlaps, best_gap, best_perp)) => match (overlaps, best_overlaps) {
+                (true, false) => true,
+                (false, true) => false,
+                (true, true) => (primary_gap, perp_offset) < (best_gap, best_perp),
+                (false, false) => primary_gap + perp_offset < best_gap + best_perp,
+            },
+        };
+
+        if is_better {
+            best = Some((candidate, overlaps, primary_gap, perp_offset));
+        }
+    }
+
+    best.map(|(candidate, ..)| candidate)
+}
+
+/// Projects `cand` relative to `origin` along `direction`.
+///
+/// Returns `(primary_gap, perpendicular_offset, overlaps)` if `cand` lies
+/// strictly toward `direction` from `origin`, or `None` if it doesn't
+/// qualify as a candidate at all.
+fn project(origin: &Rect, cand: &Rect, direction: Direction) -> Option<(i32, i32, bool)> {
+    let (primary_gap, origin_perp_center, cand_perp_center, overlaps) = match direction {
+        Direction::Right => {
+            if cand.x < origin.x + origin.w {
+                return None;
+            }
+            (
+                cand.x - (origin.x + origin.w),
+                origin.y + origin.h / 2,
+                cand.y + cand.h / 2,
+                ranges_overlap(origin.y, origin.y + origin.h, cand.y, cand.y + cand.h),
+            )
+        }
+        Direction::Left => {
+            if cand.x + cand.w > origin.x {
+                return None;
+            }
+            (
+                origin.x - (cand.x + cand.w),
+                origin.y + origin.h / 2,
+                cand.y + cand.h / 2,
+                ranges_overlap(origin.y, origin.y + origin.h, cand.y, cand.y + cand.h),
+            )
+        }
+        Direction::Down => {
+            if cand.y < origin.y + origin.h {
+                return None;
+            }
+            (
+                cand.y - (origin.y + origin.h),
+                origin.x + origin.w / 2,
+                cand.x + cand.w / 2,
+                ranges_overlap(origin.x, origin.x + origin.w, cand.x, cand.x + cand.w),
+            )
+        }
+        Direction::Up => {
+            if cand.y + cand.h > origin.y {
+                return None;
+            }
+            (
+                origin.y - (cand.y + cand.h),
+                origin.x + origin.w / 2,
+                cand.x + cand.w / 2,
+                ranges_overlap(origin.x, origin.x + origin.w, cand.x, cand.x + cand.w),
+            )
+        }
+    };
+
+    Some((primary_gap, (origin_perp_center - cand_perp_center).abs(), overlaps))
+}
+
+/// Whether two half-open intervals `[a_start, a_end)` / `[b_start, b_end)` overlap.
+fn ranges_overlap(a_start: i32, a_end: i32, b_start: i32, b_end: i32) -> bool {
+    a_start < b_end && b_start < a_end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Win(Rect);
+
+    impl Positioned for Win {
+        fn rect(&self) -> Rect {
+            self.0
+        }
+    }
+
+    fn win(x: i32, y: i32, w: i32, h: i32) -> Win {
+        Win(Rect { x, y, w, h })
+    }
+
+    #[test]
+    fn picks_overlapping_candidate_over_non_overlapping() {
+        let focused = win(0, 0, 100, 100);
+        // Directly to the right, overlapping on the y axis.
+        let aligned = win(200, 0, 100, 100);
+        // Closer along the primary axis, but doesn't overlap on the y axis at all.
+        let closer_but_offset = win(150, 500, 100, 100);
+        let candidates = vec![&closer_but_offset, &aligned];
+
+        let best = nearest_in_direction(&focused, candidates.into_iter(), Direction::Right).unwrap();
+        assert_eq!(best.0.x, 200);
+    }
+
+    #[test]
+    fn breaks_ties_by_smallest_primary_gap() {
+        let focused = win(0, 0, 100, 100);
+        let near = win(150, 0, 100, 100);
+        let far = win(300, 0, 100, 100);
+        let candidates = vec![&far, &near];
+
+        let best = nearest_in_direction(&focused, candidates.into_iter(), Direction::Right).unwrap();
+        assert_eq!(best.0.x, 150);
+    }
+
+    #[test]
+    fn breaks_overlap_ties_by_perpendicular_offset() {
+        let focused = win(0, 0, 100, 100);
+        // Same primary-axis gap, both overlap on the y axis, but one is
+        // better-aligned on it.
+        let offset = win(200, 50, 100, 100);
+        let aligned = win(200, 10, 100, 100);
+        let candidates = vec![&offset, &aligned];
+
+        let best = nearest_in_direction(&focused, candidates.into_iter(), Direction::Right).unwrap();
+        assert_eq!(best.0.y, 10);
+    }
+
+    #[test]
+    fn rejects_candidates_behind_the_focused_window() {
+        let focused = win(100, 100, 100, 100);
+        let behind = win(0, 100, 50, 100);
+        let candidates = vec![&behind];
+
+        assert!(nearest_in_direction(&focused, candidates.into_iter(), Direction::Right).is_none());
+    }
+
+    #[test]
+    fn no_candidates_returns_none() {
+        let focused = win(0, 0, 100, 100);
+        let candidates: Vec<&Win> = vec![];
+        assert!(nearest_in_direction(&focused, candidates.into_iter(), Direction::Left).is_none());
+    }
+}
@@ -1,5 +1,5 @@
 use hyprland::{
-    data::{Client, Clients, Monitor},
+    data::{Client, Clients, Monitor, Monitors},
     dispatch::{Direction, Dispatch, DispatchType, WindowIdentifier},
     keyword::Keyword,
     shared::{HyprData, HyprDataActive, HyprDataActiveOptional},
@@ -7,16 +7,33 @@ use hyprland::{
 
 // Import definitions from the command-line interface (CLI).
 // `argh` populates these structs with the arguments passed to the program.
-use crate::cli::{Command, Flags};
+use crate::cli::{Command, ConsiderFloating, ConsiderWindows, Flags};
+use crate::config::Config;
+use crate::predicate::WindowFilter;
 
 // Declare the `cli` module, which should exist in the `cli.rs` file.
 mod cli;
+mod config;
+mod daemon;
+mod geometry;
+mod predicate;
 
 // --- Main Function ---
 // Program entry point.
 fn main() -> anyhow::Result<()> {
     // 1. Parse the command-line arguments (e.g., "r", "l -s").
     let params: Flags = argh::from_env();
+
+    // The daemon subcommand never touches window state; it just runs the
+    // MRU-tracking event loop until Hyprland exits.
+    if let Command::Daemon(_) = params.cmd {
+        return daemon::run();
+    }
+
+    // Per-command defaults from `~/.config/hyprnavi-psm/config.toml`, if any.
+    // CLI flags always win; a missing or unparsable file is silently ignored.
+    let config = Config::load();
+
     // 2. Get the list of all open windows from Hyprland.
     let all_clients = Clients::get()?;
 
@@ -33,24 +50,69 @@ fn main() -> anyhow::Result<()> {
     //    It extracts parameters from each command (like `p.swap` and `p.bordersize`)
     //    and passes them to the appropriate handler function.
     match params.cmd {
-        Command::Up(p) => handle_vertical_nav(Direction::Up, p.swap, &active_client)?,
-        Command::Down(p) => handle_vertical_nav(Direction::Down, p.swap, &active_client)?,
-        Command::Left(p) => handle_horizontal_nav(
-            Direction::Left,
-            p.swap,
-            p.bordersize,
-            &active_client,
-            &active_monitor,
-            &all_clients,
-        )?,
-        Command::Right(p) => handle_horizontal_nav(
-            Direction::Right,
-            p.swap,
-            p.bordersize,
-            &active_client,
-            &active_monitor,
-            &all_clients,
-        )?,
+        Command::Up(p) => {
+            let filter = WindowFilter::from_cli(p.skip_class.clone(), p.only_class.clone());
+            handle_vertical_nav(
+                Direction::Up,
+                p.swap,
+                p.spatial,
+                ConsiderFloating::from_cli(p.include_floating, p.exclude_floating, config.include_floating),
+                p.consider.or(config.consider).unwrap_or_default(),
+                &filter,
+                &active_client,
+                &active_monitor,
+                &all_clients,
+            )?
+        }
+        Command::Down(p) => {
+            let filter = WindowFilter::from_cli(p.skip_class.clone(), p.only_class.clone());
+            handle_vertical_nav(
+                Direction::Down,
+                p.swap,
+                p.spatial,
+                ConsiderFloating::from_cli(p.include_floating, p.exclude_floating, config.include_floating),
+                p.consider.or(config.consider).unwrap_or_default(),
+                &filter,
+                &active_client,
+                &active_monitor,
+                &all_clients,
+            )?
+        }
+        Command::Left(p) => {
+            let filter = WindowFilter::from_cli(p.skip_class.clone(), p.only_class.clone());
+            handle_horizontal_nav(
+                Direction::Left,
+                p.swap,
+                p.bordersize.or(config.bordersize),
+                p.mru,
+                p.spatial,
+                ConsiderFloating::from_cli(p.include_floating, p.exclude_floating, config.include_floating),
+                p.consider.or(config.consider).unwrap_or_default(),
+                &filter,
+                &config,
+                &active_client,
+                &active_monitor,
+                &all_clients,
+            )?
+        }
+        Command::Right(p) => {
+            let filter = WindowFilter::from_cli(p.skip_class.clone(), p.only_class.clone());
+            handle_horizontal_nav(
+                Direction::Right,
+                p.swap,
+                p.bordersize.or(config.bordersize),
+                p.mru,
+                p.spatial,
+                ConsiderFloating::from_cli(p.include_floating, p.exclude_floating, config.include_floating),
+                p.consider.or(config.consider).unwrap_or_default(),
+                &filter,
+                &config,
+                &active_client,
+                &active_monitor,
+                &all_clients,
+            )?
+        }
+        Command::Daemon(_) => unreachable!("handled above before fetching window state"),
     };
 
     Ok(())
@@ -65,16 +127,27 @@ fn handle_in_empty_ws(command: &Command) -> anyhow::Result<()> {
     let direction = match command {
         Command::Right(_) | Command::Up(_) => "e+1",
         Command::Left(_) | Command::Down(_) => "e-1",
+        Command::Daemon(_) => unreachable!("handled above before fetching window state"),
     };
     Dispatch::call(DispatchType::Custom("workspace", direction))?;
     Ok(())
 }
 
 /// Handles vertical navigation (Up/Down), which has simpler logic.
+///
+/// `spatial`, when set, consults the physical monitor layout once there's no
+/// geometric candidate left on the workspace, instead of leaving vertical
+/// wrapping entirely to Hyprland.
 fn handle_vertical_nav(
     direction: Direction,
     swap: bool,
+    spatial: bool,
+    consider_floating: ConsiderFloating,
+    consider_windows: ConsiderWindows,
+    filter: &WindowFilter,
     active_client: &Client,
+    active_monitor: &Monitor,
+    all_clients: &Clients,
 ) -> anyhow::Result<()> {
     if swap {
         // For floating windows, swapping doesn't make sense, so we move the window instead.
@@ -89,10 +162,35 @@ fn handle_vertical_nav(
             // For tiled windows, we use the native swap command.
             Dispatch::call(DispatchType::SwapWindow(direction))?;
         }
-    } else {
-        // If not swapping, just move the focus.
-        Dispatch::call(DispatchType::MoveFocus(direction))?;
+        return Ok(());
     }
+
+    // Try to focus the geometrically nearest window on this workspace first;
+    // an empty candidate set is treated as an edge, same as horizontal nav.
+    let candidates = geometry_candidates(all_clients, active_client, consider_floating, filter);
+    if let Some(target) = geometry::nearest_in_direction(active_client, candidates, direction) {
+        Dispatch::call(DispatchType::FocusWindow(WindowIdentifier::Address(
+            target.address.clone(),
+        )))?;
+        return Ok(());
+    }
+
+    if spatial {
+        if let Some(focused) = focus_spatial_neighbor(
+            direction,
+            active_monitor,
+            all_clients,
+            consider_floating,
+            consider_windows,
+            filter,
+        )? {
+            return Ok(focused);
+        }
+        // No monitor in that direction: fall through to Hyprland's own wrapping.
+    }
+
+    // If not swapping, just move the focus.
+    Dispatch::call(DispatchType::MoveFocus(direction))?;
     Ok(())
 }
 
@@ -101,24 +199,22 @@ fn handle_horizontal_nav(
     direction: Direction,
     swap: bool,
     bordersize: Option<i32>,
+    mru: bool,
+    spatial: bool,
+    consider_floating: ConsiderFloating,
+    consider_windows: ConsiderWindows,
+    filter: &WindowFilter,
+    config: &Config,
     active_client: &Client,
     active_monitor: &Monitor,
     all_clients: &Clients,
 ) -> anyhow::Result<()> {
-    // Determines if we are checking the right or left screen boundary.
-    let is_checking_right_bound = match direction {
-        Direction::Right => true,
-        Direction::Left => false,
-        _ => unreachable!(),
-    };
-
     // `is_bound` checks if the active window is physically at the monitor's edge.
-    let is_at_boundary = is_bound(
-        active_client,
-        active_monitor,
-        bordersize.unwrap_or(0),
-        is_checking_right_bound,
-    );
+    let is_at_boundary = is_bound(active_client, active_monitor, bordersize.unwrap_or(0), direction);
+
+    // If the config pins this workspace to a specific monitor, prefer that
+    // over the raw directional `movewindow r/l` dispatch.
+    let affinity_target = config.monitor_for_workspace(active_client.workspace.id, &active_client.workspace.name);
 
     // Specific logic block for floating windows.
     if active_client.floating {
@@ -130,10 +226,11 @@ fn handle_horizontal_nav(
             };
             // If at the boundary, move the window to the next MONITOR.
             if is_at_boundary {
-                Dispatch::call(DispatchType::Custom(
-                    "movewindow",
-                    &format!("mon:{}", dir_char),
-                ))?;
+                let target = match affinity_target {
+                    Some(monitor) => format!("mon:{}", monitor),
+                    None => format!("mon:{}", dir_char),
+                };
+                Dispatch::call(DispatchType::Custom("movewindow", &target))?;
                 // Center the window on the new monitor for better placement.
                 Dispatch::call(DispatchType::Custom("centerwindow", ""))?;
             } else {
@@ -150,33 +247,98 @@ fn handle_horizontal_nav(
     // Logic block for tiled windows.
     if swap {
         if is_at_boundary {
-            // If at the boundary, move the window to the adjacent workspace (which could be on another monitor).
+            // If at the boundary, move the window to the adjacent workspace (which could be on
+            // another monitor), preferring the configured affinity monitor if one is set.
             let dir_char = match direction {
                 Direction::Right => "r",
                 Direction::Left => "l",
                 _ => unreachable!(),
             };
-            Dispatch::call(DispatchType::Custom("movewindow", dir_char))?;
+            let target = match affinity_target {
+                Some(monitor) => format!("mon:{}", monitor),
+                None => dir_char.to_string(),
+            };
+            Dispatch::call(DispatchType::Custom("movewindow", &target))?;
         } else {
             // Otherwise, just swap with the neighboring window on the same workspace.
             Dispatch::call(DispatchType::SwapWindow(direction))?;
         }
     } else {
-        // Focus logic
-        if is_at_boundary {
-            // At the boundary: the magic happens. We move the focus to the adjacent workspace.
-            let (prev_ws, next_ws) =
-                find_adjacent_workspaces(all_clients, active_client.workspace.id);
-            let (target_ws_id, find_rightmost) = match direction {
-                // If moving right, we focus the leftmost client of the next workspace.
-                Direction::Right => (next_ws, false),
-                // If moving left, we focus the rightmost client of the previous workspace.
-                Direction::Left => (prev_ws, true),
-                _ => unreachable!(),
+        // Focus logic: try the geometrically nearest window on this workspace
+        // first; an empty candidate set is treated as an edge.
+        let candidates = geometry_candidates(all_clients, active_client, consider_floating, filter);
+        let geometric_target = geometry::nearest_in_direction(active_client, candidates, direction);
+
+        if let Some(target) = geometric_target {
+            Dispatch::call(DispatchType::FocusWindow(WindowIdentifier::Address(
+                target.address.clone(),
+            )))?;
+        } else {
+            // No geometric candidate on this workspace: treat it as an edge.
+            // `--spatial` takes priority (jump to the physically adjacent
+            // monitor), then `--mru` (the daemon's most-recently-used
+            // workspace and, within it, window), falling back to numeric
+            // workspace wrapping and the boundary heuristic.
+            if spatial
+                && focus_spatial_neighbor(
+                    direction,
+                    active_monitor,
+                    all_clients,
+                    consider_floating,
+                    consider_windows,
+                    filter,
+                )?
+                .is_some()
+            {
+                return Ok(());
+            }
+
+            let mru_cache = mru.then(daemon::read_cache).flatten();
+            let mru_target = mru_cache
+                .as_ref()
+                .and_then(|cache| daemon::mru_adjacent_workspace(cache, active_client.workspace.id));
+
+            let target_ws_id = match mru_target {
+                Some(target_ws_id) => target_ws_id,
+                None => {
+                    let (prev_ws, next_ws) =
+                        find_adjacent_workspaces(all_clients, active_client.workspace.id);
+                    match direction {
+                        Direction::Right => next_ws,
+                        Direction::Left => prev_ws,
+                        _ => unreachable!(),
+                    }
+                }
             };
-            // Tries to find a target client on the destination workspace.
-            if let Some((l_client, r_client)) = get_bound_client(all_clients, target_ws_id, false) {
-                let target_client = if find_rightmost { r_client } else { l_client };
+            // Prefer the most-recently-used window still open on the
+            // destination workspace over the boundary heuristic, so `--mru`
+            // restores the window you were actually looking at, not just the
+            // workspace. Falls back to `arrival_client` when the daemon isn't
+            // running, the workspace has no MRU-tracked window left, or
+            // `--mru` wasn't passed.
+            let target_client = mru_cache
+                .as_ref()
+                .and_then(|cache| {
+                    daemon::mru_window_on_workspace(
+                        cache,
+                        all_clients,
+                        target_ws_id,
+                        consider_floating,
+                        filter,
+                    )
+                })
+                .or_else(|| {
+                    arrival_client(
+                        all_clients,
+                        target_ws_id,
+                        direction,
+                        consider_floating,
+                        consider_windows,
+                        filter,
+                    )
+                });
+
+            if let Some(target_client) = target_client {
                 Dispatch::call(DispatchType::FocusWindow(WindowIdentifier::Address(
                     target_client.address.clone(),
                 )))?;
@@ -184,9 +346,6 @@ fn handle_horizontal_nav(
                 // If the destination workspace is empty, just switch to it.
                 Dispatch::call(DispatchType::Custom("workspace", &target_ws_id.to_string()))?;
             }
-        } else {
-            // If not at the boundary, just move the focus to the neighboring window.
-            Dispatch::call(DispatchType::MoveFocus(direction))?;
         }
     }
     Ok(())
@@ -194,15 +353,28 @@ fn handle_horizontal_nav(
 
 // --- Helper Functions ---
 
-/// Checks if a window is physically at the edge of the monitor.
+/// Windows eligible as a geometric focus target: same workspace as
+/// `active_client`, not itself, respecting `consider_floating` and `filter`.
+fn geometry_candidates<'a>(
+    all_clients: &'a Clients,
+    active_client: &Client,
+    consider_floating: ConsiderFloating,
+    filter: &'a WindowFilter,
+) -> impl Iterator<Item = &'a Client> + 'a {
+    let my_ws = active_client.workspace.id;
+    let my_addr = active_client.address.clone();
+    all_clients.iter().filter(move |c| {
+        c.address != my_addr
+            && c.workspace.id == my_ws
+            && (!c.floating || consider_floating == ConsiderFloating::IncludeFloating)
+            && filter.allows(&c.class, &c.title)
+    })
+}
+
+/// Checks if a window is physically at the edge of the monitor in `direction`.
 /// This function is crucial as it considers gaps and reserved areas (status bars).
 #[inline]
-fn is_bound(
-    act: &Client,
-    monitor: &Monitor,
-    bordersize: i32,
-    is_checking_right_bound: bool,
-) -> bool {
+fn is_bound(act: &Client, monitor: &Monitor, bordersize: i32, direction: Direction) -> bool {
     // Gets the `gaps_out` value from Hyprland settings.
     let gaps_out = match Keyword::get("general:gaps_out") {
         Ok(value) => match value.value {
@@ -212,20 +384,95 @@ fn is_bound(
         },
         Err(_) => 0,
     };
-    // Calculates the exact X-coordinates of the usable area's left and right edges.
-    let mon_right = monitor.x + monitor.width as i32 - monitor.reserved.2 as i32 - gaps_out;
-    let mon_left = monitor.x + monitor.reserved.3 as i32 + gaps_out;
+    // Hyprland reports reserved areas (bars, etc.) in (top, bottom, right, left) order.
+    let reserved = &monitor.reserved;
 
-    // Gets the X-coordinates of the active window.
-    let act_right = (act.at.0 + act.size.0) as i32;
-    let act_left = act.at.0 as i32;
+    // Gets the coordinates of the active window.
+    let (act_left, act_top) = (act.at.0 as i32, act.at.1 as i32);
+    let act_right = act_left + act.size.0 as i32;
+    let act_bottom = act_top + act.size.1 as i32;
 
     // Compares the window edge with the monitor edge, with a tolerance (`bordersize`).
-    if is_checking_right_bound {
-        (act_right - mon_right).abs() <= bordersize
+    match direction {
+        Direction::Right => {
+            let mon_right = monitor.x + monitor.width as i32 - reserved.2 as i32 - gaps_out;
+            (act_right - mon_right).abs() <= bordersize
+        }
+        Direction::Left => {
+            let mon_left = monitor.x + reserved.3 as i32 + gaps_out;
+            (act_left - mon_left).abs() <= bordersize
+        }
+        Direction::Up => {
+            let mon_top = monitor.y + reserved.0 as i32 + gaps_out;
+            (act_top - mon_top).abs() <= bordersize
+        }
+        Direction::Down => {
+            let mon_bottom = monitor.y + monitor.height as i32 - reserved.1 as i32 - gaps_out;
+            (act_bottom - mon_bottom).abs() <= bordersize
+        }
+    }
+}
+
+/// Finds the physically adjacent monitor in `direction`, i.e. the closest
+/// monitor whose origin lies strictly further along that axis.
+fn find_monitor_in_direction<'a>(
+    monitors: &'a Monitors,
+    current: &Monitor,
+    direction: Direction,
+) -> Option<&'a Monitor> {
+    monitors
+        .iter()
+        .filter(|m| m.id != current.id)
+        .filter(|m| match direction {
+            Direction::Right => m.x > current.x,
+            Direction::Left => m.x < current.x,
+            Direction::Down => m.y > current.y,
+            Direction::Up => m.y < current.y,
+        })
+        .min_by_key(|m| match direction {
+            Direction::Right => m.x - current.x,
+            Direction::Left => current.x - m.x,
+            Direction::Down => m.y - current.y,
+            Direction::Up => current.y - m.y,
+        })
+}
+
+/// When at a monitor boundary with `--spatial`, focus the window that should
+/// receive focus on the physically adjacent monitor's active workspace (or
+/// just switch to that workspace if it's empty).
+///
+/// Returns `Ok(None)` when there's no monitor in `direction`, so callers can
+/// fall back to numeric/native wrapping.
+fn focus_spatial_neighbor(
+    direction: Direction,
+    active_monitor: &Monitor,
+    all_clients: &Clients,
+    consider_floating: ConsiderFloating,
+    consider_windows: ConsiderWindows,
+    filter: &WindowFilter,
+) -> anyhow::Result<Option<()>> {
+    let monitors = Monitors::get()?;
+    let Some(target_monitor) = find_monitor_in_direction(&monitors, active_monitor, direction)
+    else {
+        return Ok(None);
+    };
+
+    let target_ws_id = target_monitor.active_workspace.id;
+    if let Some(target_client) = arrival_client(
+        all_clients,
+        target_ws_id,
+        direction,
+        consider_floating,
+        consider_windows,
+        filter,
+    ) {
+        Dispatch::call(DispatchType::FocusWindow(WindowIdentifier::Address(
+            target_client.address.clone(),
+        )))?;
     } else {
-        (act_left - mon_left).abs() <= bordersize
+        Dispatch::call(DispatchType::Custom("workspace", &target_ws_id.to_string()))?;
     }
+    Ok(Some(()))
 }
 
 /// Finds the IDs of the previous and next workspaces in a circular manner.
@@ -256,29 +503,54 @@ fn find_adjacent_workspaces(clients: &Clients, active_ws_id: i32) -> (i32, i32)
     (sorted_ids[prev_idx], sorted_ids[next_idx])
 }
 
-/// Finds the leftmost and rightmost clients on a given workspace.
-/// Used to determine which window to focus when "jumping" from one workspace to another.
-fn get_bound_client<'a>(
+/// Finds the client that should receive focus when arriving at a workspace
+/// from `direction` (e.g. moving Right arrives at the destination's leftmost
+/// window). Used both for workspace wrapping and for `--spatial` jumps.
+///
+/// `floating` controls whether floating windows are eligible candidates, and
+/// `filter` excludes windows by class/title (e.g. a sticky picture-in-picture
+/// that shouldn't absorb focus). `consider` scopes the search to
+/// `workspace_id` alone; with `AllWorkspaces` that scoping is only relaxed to
+/// the whole layout when `workspace_id` itself has no eligible window, so a
+/// jump only lands outside the destination it computed when that
+/// destination has no eligible window.
+fn arrival_client<'a>(
     all_clients: &'a Clients,
     workspace_id: i32,
-    floating: bool,
-) -> Option<(&'a Client, &'a Client)> {
-    let ws_clients: Vec<&Client> = all_clients
+    direction: Direction,
+    floating: ConsiderFloating,
+    consider: ConsiderWindows,
+    filter: &WindowFilter,
+) -> Option<&'a Client> {
+    let eligible = |c: &&Client| {
+        (floating == ConsiderFloating::IncludeFloating || !c.floating) && filter.allows(&c.class, &c.title)
+    };
+
+    let mut ws_clients: Vec<&Client> = all_clients
         .iter()
-        .filter(|c| {
-            c.workspace.id == workspace_id
-                && !c.workspace.name.starts_with("special")
-                && c.floating == floating
-        })
+        .filter(|c| !c.workspace.name.starts_with("special") && c.workspace.id == workspace_id)
+        .filter(eligible)
         .collect();
 
-    if ws_clients.is_empty() {
-        return None;
+    if ws_clients.is_empty() && consider == ConsiderWindows::AllWorkspaces {
+        // The destination workspace is empty: widen to the whole layout
+        // rather than focusing nothing.
+        ws_clients = all_clients
+            .iter()
+            .filter(|c| !c.workspace.name.starts_with("special"))
+            .filter(eligible)
+            .collect();
     }
 
-    // Finds the client with the smallest X-coordinate (leftmost) and the largest (rightmost).
-    let left_client = ws_clients.iter().min_by_key(|c| c.at.0)?;
-    let right_client = ws_clients.iter().max_by_key(|c| c.at.0)?;
-    Some((left_client, right_client))
+    match direction {
+        // Arriving from the left edge: focus the leftmost window.
+        Direction::Right => ws_clients.into_iter().min_by_key(|c| c.at.0),
+        // Arriving from the right edge: focus the rightmost window.
+        Direction::Left => ws_clients.into_iter().max_by_key(|c| c.at.0),
+        // Arriving from the top edge: focus the topmost window.
+        Direction::Down => ws_clients.into_iter().min_by_key(|c| c.at.1),
+        // Arriving from the bottom edge: focus the bottommost window.
+        Direction::Up => ws_clients.into_iter().max_by_key(|c| c.at.1),
+    }
 }
 
@@ -0,0 +1,110 @@
+//! Class/title filtering used to skip windows during navigation.
+//!
+//! Lets users exclude (or restrict to) windows whose `class`/`title` match a
+//! glob pattern, so utility windows (e.g. a sticky picture-in-picture) don't
+//! absorb focus when wrapping between workspaces.
+
+/// A compiled `--skip-class`/`--only-class` filter.
+#[derive(Clone, Debug, Default)]
+pub enum WindowFilter {
+    /// No filtering; every window is a valid target.
+    #[default]
+    None,
+    /// Windows whose class or title match the pattern are never targets.
+    Skip(String),
+    /// Only windows whose class or title match the pattern are targets.
+    Only(String),
+}
+
+impl WindowFilter {
+    /// Build a filter from the mutually-exclusive CLI options. `skip`, if
+    /// present, wins over `only`.
+    pub fn from_cli(skip_class: Option<String>, only_class: Option<String>) -> Self {
+        match (skip_class, only_class) {
+            (Some(pattern), _) => Self::Skip(pattern),
+            (None, Some(pattern)) => Self::Only(pattern),
+            (None, None) => Self::None,
+        }
+    }
+
+    /// Returns true if a window with this `class`/`title` is a valid focus
+    /// or jump target.
+    pub fn allows(&self, class: &str, title: &str) -> bool {
+        match self {
+            Self::None => true,
+            Self::Skip(pattern) => !(glob_match(pattern, class) || glob_match(pattern, title)),
+            Self::Only(pattern) => glob_match(pattern, class) || glob_match(pattern, title),
+        }
+    }
+}
+
+/// Minimal glob matcher supporting `*` wildcards, so we don't need to pull
+/// in a regex dependency for simple class/title patterns.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    fn matches(pattern: &[u8], text: &[u8]) -> bool {
+        match pattern.first() {
+            None => text.is_empty(),
+            Some(b'*') => matches(&pattern[1..], text) || (!text.is_empty() && matches(pattern, &text[1..])),
+            Some(&c) => !text.is_empty() && c == text[0] && matches(&pattern[1..], &text[1..]),
+        }
+    }
+    matches(pattern.as_bytes(), text.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob_match_exact() {
+        assert!(glob_match("firefox", "firefox"));
+        assert!(!glob_match("firefox", "firefoxx"));
+        assert!(!glob_match("firefox", "Firefox"));
+    }
+
+    #[test]
+    fn glob_match_wildcard() {
+        assert!(glob_match("*", ""));
+        assert!(glob_match("*", "anything"));
+        assert!(glob_match("fire*", "firefox"));
+        assert!(glob_match("*fox", "firefox"));
+        assert!(glob_match("fi*fox", "firefox"));
+        assert!(!glob_match("fire*", "chromium"));
+    }
+
+    #[test]
+    fn glob_match_multiple_wildcards_backtrack() {
+        // Requires backtracking past the first `*` match to succeed.
+        assert!(glob_match("*a*b", "ab"));
+        assert!(glob_match("*a*b", "xaxb"));
+        assert!(!glob_match("*a*b", "ba"));
+    }
+
+    #[test]
+    fn filter_none_allows_everything() {
+        let filter = WindowFilter::from_cli(None, None);
+        assert!(filter.allows("anything", "anything"));
+    }
+
+    #[test]
+    fn filter_skip_checks_class_and_title() {
+        let filter = WindowFilter::from_cli(Some("mpv*".to_string()), None);
+        assert!(!filter.allows("mpv", "some title"));
+        assert!(!filter.allows("other", "mpv playing"));
+        assert!(filter.allows("firefox", "some title"));
+    }
+
+    #[test]
+    fn filter_only_checks_class_and_title() {
+        let filter = WindowFilter::from_cli(None, Some("mpv*".to_string()));
+        assert!(filter.allows("mpv", "some title"));
+        assert!(filter.allows("other", "mpv playing"));
+        assert!(!filter.allows("firefox", "some title"));
+    }
+
+    #[test]
+    fn filter_skip_wins_over_only() {
+        let filter = WindowFilter::from_cli(Some("mpv".to_string()), Some("firefox".to_string()));
+        assert!(matches!(filter, WindowFilter::Skip(_)));
+    }
+}